@@ -48,8 +48,61 @@ pub struct Board {
     by_role: ByRole<Bitboard>,
     by_color: ByColor<Bitboard>,
     occupied: Bitboard,
+    #[cfg(feature = "zobrist")]
+    zobrist: u64,
 }
 
+/// Deterministic `[Role][Color][Square]` Zobrist keys, generated at compile
+/// time from a fixed SplitMix64 seed.
+#[cfg(feature = "zobrist")]
+const ZOBRIST: [[[u64; 64]; 2]; 6] = {
+    let mut table = [[[0u64; 64]; 2]; 6];
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    let mut i = 0;
+    while i < 6 * 2 * 64 {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        table[i / (2 * 64)][(i / 64) % 2][i % 64] = z;
+        i += 1;
+    }
+    table
+};
+
+/// Error when a [`Board`] fails [`validate`](Board::validate).
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum BoardError {
+    /// A side does not have exactly one king.
+    WrongKingCount,
+    /// A side has a pawn on the first or eighth rank.
+    PawnsOnBackRank,
+    /// A side has more than 16 pieces.
+    TooManyPieces,
+    /// A side has more than 8 pawns.
+    TooManyPawns,
+    /// The side not to move is left in check.
+    OppositeCheck,
+}
+
+impl fmt::Display for BoardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BoardError::WrongKingCount => "expected exactly one king per side",
+            BoardError::PawnsOnBackRank => "pawns on backrank",
+            BoardError::TooManyPieces => "too many pieces",
+            BoardError::TooManyPawns => "too many pawns",
+            BoardError::OppositeCheck => "opponent is in check",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "std")))]
+impl std::error::Error for BoardError {}
+
 impl Board {
     pub fn new() -> Board {
         Board {
@@ -66,7 +119,10 @@ impl Board {
                 white: Bitboard(0xffff),
             },
             occupied: Bitboard(0xffff_0000_0000_ffff),
+            #[cfg(feature = "zobrist")]
+            zobrist: 0,
         }
+        .with_zobrist()
     }
 
     pub fn empty() -> Board {
@@ -74,7 +130,10 @@ impl Board {
             by_role: ByRole::default(),
             by_color: ByColor::default(),
             occupied: Bitboard::EMPTY,
+            #[cfg(feature = "zobrist")]
+            zobrist: 0,
         }
+        .with_zobrist()
     }
 
     /// Creates a board from bitboard constituents.
@@ -90,7 +149,14 @@ impl Board {
         });
         assert!(by_color.black.is_disjoint(by_color.white), "by_color not disjoint");
         assert_eq!(occupied, by_color.black | by_color.white, "by_role does not match by_color");
-        Board { by_role, by_color, occupied }
+        Board {
+            by_role,
+            by_color,
+            occupied,
+            #[cfg(feature = "zobrist")]
+            zobrist: 0,
+        }
+        .with_zobrist()
     }
 
     pub fn into_bitboards(self) -> (ByRole<Bitboard>, ByColor<Bitboard>) {
@@ -114,7 +180,10 @@ impl Board {
                 white: Bitboard(0xf0f0),
             },
             occupied: Bitboard(0xffff),
+            #[cfg(feature = "zobrist")]
+            zobrist: 0,
         }
+        .with_zobrist()
     }
 
     #[cfg(feature = "variant")]
@@ -134,7 +203,10 @@ impl Board {
                 white: Bitboard(0x0000_0066_ffff_ffff),
             },
             occupied: Bitboard(0xffff_0066_ffff_ffff),
+            #[cfg(feature = "zobrist")]
+            zobrist: 0,
         }
+        .with_zobrist()
     }
 
     #[inline]
@@ -232,12 +304,20 @@ impl Board {
             self.by_role.get_mut(p.role).toggle(sq);
             self.by_color.get_mut(p.color).toggle(sq);
             self.occupied.toggle(sq);
+            #[cfg(feature = "zobrist")]
+            {
+                self.zobrist ^= Board::zobrist_key(p.role, p.color, sq);
+            }
         }
         piece
     }
 
     #[inline]
     pub fn discard_piece_at(&mut self, sq: Square) {
+        #[cfg(feature = "zobrist")]
+        if let Some(p) = self.piece_at(sq) {
+            self.zobrist ^= Board::zobrist_key(p.role, p.color, sq);
+        }
         self.by_role.as_mut().for_each(|r| r.discard(sq));
         self.by_color.as_mut().for_each(|c| c.discard(sq));
         self.occupied.discard(sq);
@@ -249,6 +329,10 @@ impl Board {
         self.by_role.get_mut(role).toggle(sq);
         self.by_color.get_mut(color).toggle(sq);
         self.occupied.toggle(sq);
+        #[cfg(feature = "zobrist")]
+        {
+            self.zobrist ^= Board::zobrist_key(role, color, sq);
+        }
     }
 
     #[inline]
@@ -293,6 +377,159 @@ impl Board {
         ByColor::new_with(|color| self.material_side(color))
     }
 
+    /// Static exchange evaluation: the net material swing, in units of
+    /// `values`, of the best capture sequence on `target`.
+    ///
+    /// The value of the piece initially standing on `target` (if any) counts
+    /// as the first capture. A positive result means the side that moves first
+    /// comes out ahead, assuming both sides keep recapturing with their
+    /// least-valuable attacker as long as it is profitable.
+    ///
+    /// X-ray attackers revealed behind a capturing slider are taken into
+    /// account. Promotions and en passant are ignored.
+    pub fn see(&self, target: Square, values: ByRole<i32>) -> i32 {
+        let mut occupied = self.occupied;
+        let mut attackers = self.attacks_to(target, Color::White, occupied)
+            | self.attacks_to(target, Color::Black, occupied);
+
+        let mut side = match self.piece_at(target) {
+            Some(piece) => !piece.color,
+            None => return 0,
+        };
+
+        let mut gain = [0i32; 32];
+        gain[0] = *values.get(self.role_at(target).expect("occupied target"));
+        let mut d = 0;
+
+        loop {
+            let side_attackers = attackers & self.by_color(side);
+            let least = Role::ALL
+                .into_iter()
+                .find_map(|role| (side_attackers & self.by_role(role)).first().map(|sq| (role, sq)));
+            let (role, from) = match least {
+                Some(least) => least,
+                None => break,
+            };
+            // The king may only capture when the opponent has no attacker left
+            // that could recapture it.
+            if role == Role::King && !(attackers & self.by_color(!side)).is_empty() {
+                break;
+            }
+            d += 1;
+            gain[d] = *values.get(role) - gain[d - 1];
+            occupied.toggle(from);
+            attackers.toggle(from);
+            // Re-reveal sliders lined up behind the captured attacker.
+            attackers |= ((attacks::rook_attacks(target, occupied) & self.rooks_and_queens())
+                | (attacks::bishop_attacks(target, occupied) & self.bishops_and_queens()))
+                & occupied;
+            side = !side;
+        }
+
+        if d == 0 {
+            // No capture was possible on `target`.
+            return 0;
+        }
+
+        while d > 1 {
+            d -= 1;
+            gain[d - 1] = -i32::max(-gain[d - 1], gain[d]);
+        }
+        gain[0]
+    }
+
+    /// The enemy pieces giving check to `king_color`'s king.
+    ///
+    /// Returns an empty bitboard if that side has no king.
+    #[inline]
+    pub fn checkers(&self, king_color: Color) -> Bitboard {
+        self.king_of(king_color).map_or(Bitboard::EMPTY, |king| {
+            self.attacks_to(king, !king_color, self.occupied)
+        })
+    }
+
+    /// Pinned pieces and pinners relative to `king_color`'s king.
+    ///
+    /// Returns `(pinned, pinners)`, where `pinned` are the friendly pieces that
+    /// are absolutely pinned to the king and `pinners` are the enemy sliders
+    /// doing the pinning. Returns two empty bitboards if that side has no king.
+    pub fn pinned(&self, king_color: Color) -> (Bitboard, Bitboard) {
+        let mut pinned = Bitboard::EMPTY;
+        let mut pinners = Bitboard::EMPTY;
+        if let Some(king) = self.king_of(king_color) {
+            let snipers = ((attacks::rook_attacks(king, Bitboard::EMPTY) & self.rooks_and_queens())
+                | (attacks::bishop_attacks(king, Bitboard::EMPTY) & self.bishops_and_queens()))
+                & self.by_color(!king_color);
+            for sniper in snipers {
+                let between = attacks::between(king, sniper) & self.occupied;
+                if let Some(blocker) = between.single_square() {
+                    if self.by_color(king_color).contains(blocker) {
+                        pinned.toggle(blocker);
+                        pinners.toggle(sniper);
+                    }
+                }
+            }
+        }
+        (pinned, pinners)
+    }
+
+    /// The pieces of `color` bearing on `sq`.
+    ///
+    /// This is a readability alias for [`attacks_to`](Self::attacks_to), which
+    /// already reports every piece of the given color bearing on `sq`
+    /// regardless of its occupant; the name simply reads better when `color`
+    /// is the side owning `sq` and the intent is to count its defenders.
+    #[inline]
+    pub fn defenders_to(&self, sq: Square, color: Color, occupied: Bitboard) -> Bitboard {
+        self.attacks_to(sq, color, occupied)
+    }
+
+    /// Every square attacked or defended by a piece of `color`.
+    ///
+    /// This is the control map: the union of the attacks of all of `color`'s
+    /// pieces, with pawns contributing their capture squares.
+    pub fn attacked_by(&self, color: Color) -> Bitboard {
+        (self.by_color(color))
+            .into_iter()
+            .fold(Bitboard::EMPTY, |acc, sq| acc | self.attacks_from(sq))
+    }
+
+    /// Checks the consistency properties that any legal piece placement must
+    /// satisfy, independently of castling and en passant state.
+    ///
+    /// Each side must have exactly one king, no more than 16 pieces of which at
+    /// most 8 are pawns, and no pawns on the backranks. If `turn` is given, the
+    /// side *not* to move must not be left in check.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BoardError`] describing the first violated property.
+    pub fn validate(&self, turn: Option<Color>) -> Result<(), BoardError> {
+        for color in Color::ALL {
+            if (self.by_role.king & self.by_color(color)).count() != 1 {
+                return Err(BoardError::WrongKingCount);
+            }
+            if self.by_color(color).count() > 16 {
+                return Err(BoardError::TooManyPieces);
+            }
+            if (self.pawns() & self.by_color(color)).count() > 8 {
+                return Err(BoardError::TooManyPawns);
+            }
+        }
+
+        if !self.pawns().is_disjoint(Bitboard::BACKRANKS) {
+            return Err(BoardError::PawnsOnBackRank);
+        }
+
+        if let Some(turn) = turn {
+            if !self.checkers(!turn).is_empty() {
+                return Err(BoardError::OppositeCheck);
+            }
+        }
+
+        Ok(())
+    }
+
     fn transform<F>(&mut self, f: F)
     where
         F: Fn(Bitboard) -> Bitboard,
@@ -302,6 +539,10 @@ impl Board {
         self.by_role.as_mut().for_each(|r| *r = f(*r));
         self.by_color.as_mut().for_each(|c| *c = f(*c));
         self.occupied = self.by_color.white | self.by_color.black;
+        #[cfg(feature = "zobrist")]
+        {
+            self.zobrist = self.compute_zobrist();
+        }
     }
 
     /// Mirror the board vertically. See [`Bitboard::flip_vertical`].
@@ -352,6 +593,59 @@ impl Board {
             .last()
             .and_then(|sq| self.remove_piece_at(sq).map(|piece| (sq, piece)))
     }
+
+    /// Incrementally maintained Zobrist hash of the piece placement.
+    ///
+    /// The key is kept in sync on every mutation, so it always matches a fresh
+    /// recomputation. It is independent of the structural [`Hash`](core::hash::Hash)
+    /// implementation.
+    #[cfg(feature = "zobrist")]
+    #[cfg_attr(docs_rs, doc(cfg(feature = "zobrist")))]
+    #[inline]
+    pub fn zobrist_hash(&self) -> u64 {
+        self.zobrist
+    }
+
+    #[cfg(feature = "zobrist")]
+    #[inline]
+    fn zobrist_key(role: Role, color: Color, sq: Square) -> u64 {
+        let role = match role {
+            Role::Pawn => 0,
+            Role::Knight => 1,
+            Role::Bishop => 2,
+            Role::Rook => 3,
+            Role::Queen => 4,
+            Role::King => 5,
+        };
+        let color = match color {
+            Color::Black => 0,
+            Color::White => 1,
+        };
+        ZOBRIST[role][color][usize::from(sq)]
+    }
+
+    #[cfg(feature = "zobrist")]
+    fn compute_zobrist(&self) -> u64 {
+        self.occupied.into_iter().fold(0, |hash, sq| {
+            match self.piece_at(sq) {
+                Some(piece) => hash ^ Board::zobrist_key(piece.role, piece.color, sq),
+                None => hash,
+            }
+        })
+    }
+
+    #[cfg(feature = "zobrist")]
+    #[inline]
+    fn with_zobrist(mut self) -> Board {
+        self.zobrist = self.compute_zobrist();
+        self
+    }
+
+    #[cfg(not(feature = "zobrist"))]
+    #[inline]
+    fn with_zobrist(self) -> Board {
+        self
+    }
 }
 
 impl Default for Board {
@@ -512,4 +806,120 @@ mod tests {
         let (by_role, by_color) = Board::default().into_bitboards();
         assert_eq!(Board::default(), Board::from_bitboards(by_role, by_color));
     }
+
+    #[test]
+    fn test_see() {
+        let values = ByRole {
+            pawn: 1,
+            knight: 3,
+            bishop: 3,
+            rook: 5,
+            queen: 9,
+            king: 0,
+        };
+
+        // Rook grabs an undefended pawn.
+        let board: Board = "8/p7/8/8/8/8/8/R7".parse().expect("valid fen");
+        assert_eq!(board.see(Square::A7, values), 1);
+
+        // Pawn takes a knight defended by a pawn: the recapture is forced, so
+        // the exchange nets knight minus pawn.
+        let board: Board = "8/8/5p2/4n3/3P4/8/8/8".parse().expect("valid fen");
+        assert_eq!(board.see(Square::E5, values), 2);
+
+        // The back rook only joins the exchange once the front rook captures,
+        // which an x-ray-unaware evaluation would miss.
+        let board: Board = "r7/8/8/p7/8/8/R7/R7".parse().expect("valid fen");
+        assert_eq!(board.see(Square::A5, values), 1);
+    }
+
+    #[test]
+    fn test_checkers() {
+        let board: Board = "4r3/8/8/8/8/8/8/4K3".parse().expect("valid fen");
+        assert_eq!(board.checkers(White), Bitboard::from_square(Square::E8));
+        assert_eq!(board.checkers(Black), Bitboard::EMPTY);
+    }
+
+    #[test]
+    fn test_pinned() {
+        let board: Board = "4r3/8/8/8/8/8/4N3/4K3".parse().expect("valid fen");
+        assert_eq!(
+            board.pinned(White),
+            (
+                Bitboard::from_square(Square::E2),
+                Bitboard::from_square(Square::E8),
+            )
+        );
+    }
+
+    #[test]
+    fn test_defenders_to() {
+        // The rook defends the friendly pawn standing on a4.
+        let board: Board = "8/8/8/8/P7/8/8/R7".parse().expect("valid fen");
+        assert!(board
+            .defenders_to(Square::A4, White, board.occupied())
+            .contains(Square::A1));
+    }
+
+    #[test]
+    fn test_attacked_by() {
+        let board: Board = "8/8/8/8/P7/8/8/R7".parse().expect("valid fen");
+        let attacked = board.attacked_by(White);
+        assert!(attacked.contains(Square::B5)); // pawn capture
+        assert!(attacked.contains(Square::A2)); // rook along the file
+        assert!(attacked.contains(Square::A4)); // defends its own pawn
+        assert!(!attacked.contains(Square::A5)); // blocked beyond the pawn
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(Board::new().validate(Some(White)), Ok(()));
+
+        let two_kings: Board = "4k3/8/8/8/8/8/8/3KK3".parse().expect("valid fen");
+        assert_eq!(two_kings.validate(None), Err(BoardError::WrongKingCount));
+
+        let back_rank: Board = "4k3/8/8/8/8/8/8/P3K3".parse().expect("valid fen");
+        assert_eq!(back_rank.validate(None), Err(BoardError::PawnsOnBackRank));
+
+        let too_many: Board = "4k3/8/8/8/8/NNNNNNNN/PPPPPPPP/4K3"
+            .parse()
+            .expect("valid fen");
+        assert_eq!(too_many.validate(None), Err(BoardError::TooManyPieces));
+
+        let too_many_pawns: Board = "4k3/8/8/8/8/P7/PPPPPPPP/4K3"
+            .parse()
+            .expect("valid fen");
+        assert_eq!(
+            too_many_pawns.validate(None),
+            Err(BoardError::TooManyPawns)
+        );
+
+        let opposite_check: Board = "4k3/8/8/8/8/8/4R3/K7".parse().expect("valid fen");
+        assert_eq!(
+            opposite_check.validate(Some(White)),
+            Err(BoardError::OppositeCheck)
+        );
+    }
+
+    #[cfg(feature = "zobrist")]
+    #[test]
+    fn test_zobrist_incremental() {
+        let mut board = Board::new();
+        assert_eq!(board.zobrist_hash(), board.compute_zobrist());
+
+        board.set_piece_at(Square::E4, White.pawn());
+        assert_eq!(board.zobrist_hash(), board.compute_zobrist());
+
+        board.remove_piece_at(Square::E2);
+        assert_eq!(board.zobrist_hash(), board.compute_zobrist());
+
+        board.set_piece_at(Square::A1, Black.queen()); // overwrite the white rook
+        assert_eq!(board.zobrist_hash(), board.compute_zobrist());
+
+        board.discard_piece_at(Square::D7);
+        assert_eq!(board.zobrist_hash(), board.compute_zobrist());
+
+        board.flip_vertical();
+        assert_eq!(board.zobrist_hash(), board.compute_zobrist());
+    }
 }